@@ -1,12 +1,102 @@
 // dllmain.cpp : Defines the entry point for the DLL application.
 #include "DifBuilderLib.h"
 #include <DIFBuilder/DIFBuilder.hpp>
+#include <cstdio>
+#include <string>
+
+static thread_local std::string lastError;
+
+static int fail(int status, const std::string &message)
+{
+	lastError = message;
+	return status;
+}
+
+static DIF::InteriorPathFollower *get_path_follower(DIF::DIF *dif, int followerIndex, const char *caller)
+{
+	if (dif == NULL)
+	{
+		fail(DIFBUILDER_INVALID_ARGUMENT, std::string(caller) + ": null dif");
+		return NULL;
+	}
+	if (followerIndex < 0 || followerIndex >= (int)dif->interiorPathFollower.size())
+	{
+		fail(DIFBUILDER_INVALID_ARGUMENT, std::string(caller) + ": path follower index " + std::to_string(followerIndex) + " out of range");
+		return NULL;
+	}
+	return &dif->interiorPathFollower[followerIndex];
+}
+
+static DIF::GameEntity *get_game_entity(DIF::DIF *dif, int entityIndex, const char *caller)
+{
+	if (dif == NULL)
+	{
+		fail(DIFBUILDER_INVALID_ARGUMENT, std::string(caller) + ": null dif");
+		return NULL;
+	}
+	if (entityIndex < 0 || entityIndex >= (int)dif->gameEntity.size())
+	{
+		fail(DIFBUILDER_INVALID_ARGUMENT, std::string(caller) + ": game entity index " + std::to_string(entityIndex) + " out of range");
+		return NULL;
+	}
+	return &dif->gameEntity[entityIndex];
+}
+
+static int write_dif_to(DIF::DIF *dif, char *path, DIF::Version &ver, const std::string &caller)
+{
+	std::ofstream outStr;
+	outStr.open(path, std::ios::out | std::ios::binary);
+	if (!outStr.is_open())
+		return fail(DIFBUILDER_ERROR, caller + ": could not open " + path + " for writing");
+
+	std::string error;
+	try
+	{
+		dif->write(outStr, ver);
+		outStr.close();
+		if (outStr.fail())
+			error = caller + ": failed writing " + path;
+	}
+	catch (const std::exception &e)
+	{
+		error = caller + ": " + e.what();
+	}
+	catch (...)
+	{
+		error = caller + ": unknown error";
+	}
+
+	if (error.empty())
+		return DIFBUILDER_OK;
+
+	// Don't leave a truncated dif behind
+	outStr.close();
+	std::remove(path);
+	return fail(DIFBUILDER_ERROR, error);
+}
 
 extern "C"
 {
+	const char *get_last_error_message()
+	{
+		return lastError.c_str();
+	}
+
 	DIF::DIFBuilder *new_difbuilder()
 	{
-		return new DIF::DIFBuilder();
+		try
+		{
+			return new DIF::DIFBuilder();
+		}
+		catch (const std::exception &e)
+		{
+			fail(DIFBUILDER_ERROR, std::string("new_difbuilder: ") + e.what());
+		}
+		catch (...)
+		{
+			fail(DIFBUILDER_ERROR, "new_difbuilder: unknown error");
+		}
+		return NULL;
 	}
 
 	void dispose_difbuilder(DIF::DIFBuilder *difbuilder)
@@ -21,8 +111,11 @@ extern "C"
 			delete dif;
 	}
 
-	void add_triangle(DIF::DIFBuilder *builder, float *p1, float *p2, float *p3, float *uv1, float *uv2, float *uv3, float *n, char *material)
+	int add_triangle(DIF::DIFBuilder *builder, float *p1, float *p2, float *p3, float *uv1, float *uv2, float *uv3, float *n, char *material)
 	{
+		if (builder == NULL || p1 == NULL || p2 == NULL || p3 == NULL || uv1 == NULL || uv2 == NULL || uv3 == NULL || n == NULL || material == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "add_triangle: null argument");
+
 		DIF::DIFBuilder::Triangle tri = DIF::DIFBuilder::Triangle();
 		tri.points[0].vertex = glm::vec3(p1[0], p1[1], p1[2]);
 		tri.points[1].vertex = glm::vec3(p2[0], p2[1], p2[2]);
@@ -36,85 +129,634 @@ extern "C"
 		tri.points[1].normal = tri.points[0].normal;
 		tri.points[2].normal = tri.points[0].normal;
 
-		builder->addTriangle(tri, std::string(material));
+		try
+		{
+			builder->addTriangle(tri, std::string(material));
+		}
+		catch (const std::exception &e)
+		{
+			return fail(DIFBUILDER_ERROR, std::string("add_triangle: ") + e.what());
+		}
+		catch (...)
+		{
+			return fail(DIFBUILDER_ERROR, "add_triangle: unknown error");
+		}
+		return DIFBUILDER_OK;
 	}
 
 	DIF::DIF *build(DIF::DIFBuilder *builder)
 	{
-		DIF::DIF dif;
-		builder->build(dif);
-		return new DIF::DIF(dif);
+		if (builder == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "build: null builder");
+			return NULL;
+		}
+
+		try
+		{
+			DIF::DIF dif;
+			builder->build(dif);
+			return new DIF::DIF(dif);
+		}
+		catch (const std::exception &e)
+		{
+			fail(DIFBUILDER_ERROR, std::string("build: ") + e.what());
+		}
+		catch (...)
+		{
+			fail(DIFBUILDER_ERROR, "build: unknown error");
+		}
+		return NULL;
 	}
 
-	void add_pathed_interior(DIF::DIFBuilder *builder, DIF::DIF *dif, std::vector<DIF::DIFBuilder::Marker> *markerlist)
+	int add_pathed_interior(DIF::DIFBuilder *builder, DIF::DIF *dif, std::vector<DIF::DIFBuilder::Marker> *markerlist)
 	{
-		builder->addPathedInterior(dif->interior[0], *markerlist);
+		if (builder == NULL || dif == NULL || markerlist == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "add_pathed_interior: null argument");
+		if (dif->interior.empty())
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "add_pathed_interior: dif has no interior");
+
+		try
+		{
+			builder->addPathedInterior(dif->interior[0], *markerlist);
+		}
+		catch (const std::exception &e)
+		{
+			return fail(DIFBUILDER_ERROR, std::string("add_pathed_interior: ") + e.what());
+		}
+		catch (...)
+		{
+			return fail(DIFBUILDER_ERROR, "add_pathed_interior: unknown error");
+		}
+		return DIFBUILDER_OK;
 	}
 
-	void add_trigger(DIF::DIFBuilder *difbuilder, float *position, char *name, char *datablock, DIF::Dictionary *props)
+	int add_trigger(DIF::DIFBuilder *difbuilder, float *position, char *name, char *datablock, DIF::Dictionary *props)
 	{
-		DIF::DIFBuilder::Trigger trigger;
-		trigger.name = std::string(name);
-		trigger.datablock = std::string(datablock);
-		trigger.properties = DIF::Dictionary(*props);
-		trigger.position = glm::vec3(position[0], position[1], position[2]);
-		difbuilder->addTrigger(trigger);
+		if (difbuilder == NULL || position == NULL || name == NULL || datablock == NULL || props == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "add_trigger: null argument");
+
+		try
+		{
+			DIF::DIFBuilder::Trigger trigger;
+			trigger.name = std::string(name);
+			trigger.datablock = std::string(datablock);
+			trigger.properties = DIF::Dictionary(*props);
+			trigger.position = glm::vec3(position[0], position[1], position[2]);
+			difbuilder->addTrigger(trigger);
+		}
+		catch (const std::exception &e)
+		{
+			return fail(DIFBUILDER_ERROR, std::string("add_trigger: ") + e.what());
+		}
+		catch (...)
+		{
+			return fail(DIFBUILDER_ERROR, "add_trigger: unknown error");
+		}
+		return DIFBUILDER_OK;
 	}
 
-	void write_dif(DIF::DIF *dif, char *path)
+	int write_dif(DIF::DIF *dif, char *path)
 	{
-		std::ofstream outStr;
-		outStr.open(path, std::ios::out | std::ios::binary);
+		if (dif == NULL || path == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "write_dif: null argument");
+
 		DIF::Version ver;
 		ver.dif.type = DIF::Version::DIFVersion::MBG;
-		dif->write(outStr, ver);
+		return write_dif_to(dif, path, ver, "write_dif");
+	}
+
+	int write_dif_versioned(DIF::DIF *dif, char *path, int engine, int interiorVersion)
+	{
+		if (dif == NULL || path == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "write_dif_versioned: null argument");
+
+		// Interior versions each engine loads: MBG only has version 0, TGE reads 0 to 5
+		// and TGEA reads 10 to 14
+		DIF::Version ver;
+		int minVersion, maxVersion;
+		switch (engine)
+		{
+		case DIFBUILDER_ENGINE_MBG:
+			ver.dif.type = DIF::Version::DIFVersion::MBG;
+			ver.interior.type = DIF::Version::InteriorVersion::MBG;
+			minVersion = 0;
+			maxVersion = 0;
+			break;
+		case DIFBUILDER_ENGINE_TGE:
+			ver.dif.type = DIF::Version::DIFVersion::TGE;
+			ver.interior.type = DIF::Version::InteriorVersion::TGE;
+			minVersion = 0;
+			maxVersion = 5;
+			break;
+		case DIFBUILDER_ENGINE_TGEA:
+			ver.dif.type = DIF::Version::DIFVersion::TGEA;
+			ver.interior.type = DIF::Version::InteriorVersion::TGEA;
+			minVersion = 10;
+			maxVersion = 14;
+			break;
+		default:
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "write_dif_versioned: unknown engine " + std::to_string(engine));
+		}
+		if (interiorVersion < minVersion || interiorVersion > maxVersion)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "write_dif_versioned: engine " + std::to_string(engine) + " does not load interior version " + std::to_string(interiorVersion) + ", expected " + std::to_string(minVersion) + " to " + std::to_string(maxVersion));
+		ver.interior.version = interiorVersion;
+
+		return write_dif_to(dif, path, ver, "write_dif_versioned");
 	}
 
 	std::vector<DIF::DIFBuilder::Marker> *new_marker_list()
 	{
-		return new std::vector<DIF::DIFBuilder::Marker>();
+		try
+		{
+			return new std::vector<DIF::DIFBuilder::Marker>();
+		}
+		catch (const std::exception &e)
+		{
+			fail(DIFBUILDER_ERROR, std::string("new_marker_list: ") + e.what());
+		}
+		catch (...)
+		{
+			fail(DIFBUILDER_ERROR, "new_marker_list: unknown error");
+		}
+		return NULL;
 	}
 
 	void dispose_marker_list(std::vector<DIF::DIFBuilder::Marker> *markerlist)
 	{
-		delete markerlist;
+		if (markerlist != NULL)
+			delete markerlist;
 	}
 
-	void push_marker(std::vector<DIF::DIFBuilder::Marker> *markerlist, float *pos, int msToNext, int initialPathPosition)
+	int push_marker(std::vector<DIF::DIFBuilder::Marker> *markerlist, float *pos, int msToNext, int initialPathPosition, int smoothing)
 	{
-		DIF::DIFBuilder::Marker m;
-		m.position = glm::vec3(pos[0], pos[1], pos[2]);
-		m.msToNext = msToNext;
-		m.smoothing = 0;
-		m.initialPathPosition = initialPathPosition;
-		markerlist->push_back(m);
+		if (markerlist == NULL || pos == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "push_marker: null argument");
+		if (smoothing < DIFBUILDER_SMOOTHING_LINEAR || smoothing > DIFBUILDER_SMOOTHING_ACCELERATE)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "push_marker: invalid smoothing type " + std::to_string(smoothing));
+
+		try
+		{
+			DIF::DIFBuilder::Marker m;
+			m.position = glm::vec3(pos[0], pos[1], pos[2]);
+			m.msToNext = msToNext;
+			m.smoothing = smoothing;
+			m.initialPathPosition = initialPathPosition;
+			markerlist->push_back(m);
+		}
+		catch (const std::exception &e)
+		{
+			return fail(DIFBUILDER_ERROR, std::string("push_marker: ") + e.what());
+		}
+		catch (...)
+		{
+			return fail(DIFBUILDER_ERROR, "push_marker: unknown error");
+		}
+		return DIFBUILDER_OK;
 	}
 
-	void add_game_entity(DIF::DIF *dif, char *gameClass, char *datablock, float *pos, DIF::Dictionary *dict)
+	int add_game_entity(DIF::DIF *dif, char *gameClass, char *datablock, float *pos, DIF::Dictionary *dict)
 	{
-		DIF::GameEntity g;
-		g.datablock = std::string(datablock);
-		g.gameClass = std::string(gameClass);
-		g.position = glm::vec3(pos[0], pos[1], pos[2]);
-		g.properties = DIF::Dictionary(*dict);
-		g.properties.push_back(std::pair<std::string, std::string>(std::string("static"), std::string("1")));
-		g.properties.push_back(std::pair<std::string, std::string>(std::string("rotate"), std::string("1")));
-		dif->readGameEntities = 2;
-		dif->gameEntity.push_back(g);
+		if (dif == NULL || gameClass == NULL || datablock == NULL || pos == NULL || dict == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "add_game_entity: null argument");
+
+		try
+		{
+			DIF::GameEntity g;
+			g.datablock = std::string(datablock);
+			g.gameClass = std::string(gameClass);
+			g.position = glm::vec3(pos[0], pos[1], pos[2]);
+			g.properties = DIF::Dictionary(*dict);
+			g.properties.push_back(std::pair<std::string, std::string>(std::string("static"), std::string("1")));
+			g.properties.push_back(std::pair<std::string, std::string>(std::string("rotate"), std::string("1")));
+			dif->readGameEntities = 2;
+			dif->gameEntity.push_back(g);
+		}
+		catch (const std::exception &e)
+		{
+			return fail(DIFBUILDER_ERROR, std::string("add_game_entity: ") + e.what());
+		}
+		catch (...)
+		{
+			return fail(DIFBUILDER_ERROR, "add_game_entity: unknown error");
+		}
+		return DIFBUILDER_OK;
 	}
 
 	DIF::Dictionary *new_dict()
 	{
-		return new DIF::Dictionary();
+		try
+		{
+			return new DIF::Dictionary();
+		}
+		catch (const std::exception &e)
+		{
+			fail(DIFBUILDER_ERROR, std::string("new_dict: ") + e.what());
+		}
+		catch (...)
+		{
+			fail(DIFBUILDER_ERROR, "new_dict: unknown error");
+		}
+		return NULL;
 	}
 
 	void dispose_dict(DIF::Dictionary *dict)
 	{
-		delete dict;
+		if (dict != NULL)
+			delete dict;
+	}
+
+	int add_dict_kvp(DIF::Dictionary *dict, char *key, char *value)
+	{
+		if (dict == NULL || key == NULL || value == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "add_dict_kvp: null argument");
+
+		try
+		{
+			dict->push_back(std::pair<std::string, std::string>(std::string(key), std::string(value)));
+		}
+		catch (const std::exception &e)
+		{
+			return fail(DIFBUILDER_ERROR, std::string("add_dict_kvp: ") + e.what());
+		}
+		catch (...)
+		{
+			return fail(DIFBUILDER_ERROR, "add_dict_kvp: unknown error");
+		}
+		return DIFBUILDER_OK;
+	}
+
+	DIF::DIF *read_dif(char *path)
+	{
+		if (path == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "read_dif: null path");
+			return NULL;
+		}
+
+		std::ifstream inStr;
+		inStr.open(path, std::ios::in | std::ios::binary);
+		if (!inStr.is_open())
+		{
+			fail(DIFBUILDER_ERROR, std::string("read_dif: could not open ") + path);
+			return NULL;
+		}
+
+		DIF::DIF *dif = NULL;
+		try
+		{
+			dif = new DIF::DIF();
+			DIF::Version ver;
+			if (dif->read(inStr, ver))
+				return dif;
+			fail(DIFBUILDER_ERROR, std::string("read_dif: ") + path + " is not a valid dif");
+		}
+		catch (const std::exception &e)
+		{
+			fail(DIFBUILDER_ERROR, std::string("read_dif: ") + e.what());
+		}
+		catch (...)
+		{
+			fail(DIFBUILDER_ERROR, "read_dif: unknown error");
+		}
+		delete dif;
+		return NULL;
+	}
+
+	// The count accessors below return -1 on failure, see get_last_error_message
+
+	int dif_interior_count(DIF::DIF *dif)
+	{
+		if (dif == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "dif_interior_count: null dif");
+			return -1;
+		}
+		return (int)dif->interior.size();
 	}
 
-	void add_dict_kvp(DIF::Dictionary *dict, char *key, char *value)
+	// Returns NULL on failure, the interior is owned by the dif
+	DIF::Interior *dif_get_interior(DIF::DIF *dif, int interiorIndex)
 	{
-		dict->push_back(std::pair<std::string, std::string>(std::string(key), std::string(value)));
+		if (dif == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "dif_get_interior: null dif");
+			return NULL;
+		}
+		if (interiorIndex < 0 || interiorIndex >= (int)dif->interior.size())
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "dif_get_interior: interior index " + std::to_string(interiorIndex) + " out of range");
+			return NULL;
+		}
+		return &dif->interior[interiorIndex];
+	}
+
+	int dif_sub_object_count(DIF::DIF *dif)
+	{
+		if (dif == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "dif_sub_object_count: null dif");
+			return -1;
+		}
+		return (int)dif->subObject.size();
+	}
+
+	// Returns NULL on failure, the sub object is owned by the dif
+	DIF::Interior *dif_get_sub_object(DIF::DIF *dif, int subObjectIndex)
+	{
+		if (dif == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "dif_get_sub_object: null dif");
+			return NULL;
+		}
+		if (subObjectIndex < 0 || subObjectIndex >= (int)dif->subObject.size())
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "dif_get_sub_object: sub object index " + std::to_string(subObjectIndex) + " out of range");
+			return NULL;
+		}
+		return &dif->subObject[subObjectIndex];
+	}
+
+	int interior_get_point_count(DIF::Interior *interior)
+	{
+		if (interior == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_point_count: null interior");
+			return -1;
+		}
+		return (int)interior->point.size();
+	}
+
+	// points must hold 3 floats for each of capacity points
+	int interior_get_points(DIF::Interior *interior, float *points, int capacity)
+	{
+		if (interior == NULL || points == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_points: null argument");
+		if (capacity < (int)interior->point.size())
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_points: capacity " + std::to_string(capacity) + " is less than " + std::to_string(interior->point.size()) + " points");
+
+		for (size_t i = 0; i < interior->point.size(); i++)
+		{
+			points[i * 3 + 0] = interior->point[i].x;
+			points[i * 3 + 1] = interior->point[i].y;
+			points[i * 3 + 2] = interior->point[i].z;
+		}
+		return DIFBUILDER_OK;
+	}
+
+	int interior_get_normal_count(DIF::Interior *interior)
+	{
+		if (interior == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_normal_count: null interior");
+			return -1;
+		}
+		return (int)interior->normal.size();
+	}
+
+	// normals must hold 3 floats for each of capacity normals
+	int interior_get_normals(DIF::Interior *interior, float *normals, int capacity)
+	{
+		if (interior == NULL || normals == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_normals: null argument");
+		if (capacity < (int)interior->normal.size())
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_normals: capacity " + std::to_string(capacity) + " is less than " + std::to_string(interior->normal.size()) + " normals");
+
+		for (size_t i = 0; i < interior->normal.size(); i++)
+		{
+			normals[i * 3 + 0] = interior->normal[i].x;
+			normals[i * 3 + 1] = interior->normal[i].y;
+			normals[i * 3 + 2] = interior->normal[i].z;
+		}
+		return DIFBUILDER_OK;
+	}
+
+	int interior_get_plane_count(DIF::Interior *interior)
+	{
+		if (interior == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_plane_count: null interior");
+			return -1;
+		}
+		return (int)interior->plane.size();
+	}
+
+	// normalIndices and distances must each hold capacity entries, normal indices are not checked against the normals
+	int interior_get_planes(DIF::Interior *interior, int *normalIndices, float *distances, int capacity)
+	{
+		if (interior == NULL || normalIndices == NULL || distances == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_planes: null argument");
+		if (capacity < (int)interior->plane.size())
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_planes: capacity " + std::to_string(capacity) + " is less than " + std::to_string(interior->plane.size()) + " planes");
+
+		for (size_t i = 0; i < interior->plane.size(); i++)
+		{
+			normalIndices[i] = (int)interior->plane[i].normalIndex;
+			distances[i] = interior->plane[i].planeDistance;
+		}
+		return DIFBUILDER_OK;
+	}
+
+	int interior_get_tex_gen_count(DIF::Interior *interior)
+	{
+		if (interior == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_tex_gen_count: null interior");
+			return -1;
+		}
+		return (int)interior->texGenEq.size();
+	}
+
+	// texGens must hold 8 floats for each of capacity equations, planeX (x, y, z, d) followed by planeY
+	int interior_get_tex_gens(DIF::Interior *interior, float *texGens, int capacity)
+	{
+		if (interior == NULL || texGens == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_tex_gens: null argument");
+		if (capacity < (int)interior->texGenEq.size())
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_tex_gens: capacity " + std::to_string(capacity) + " is less than " + std::to_string(interior->texGenEq.size()) + " equations");
+
+		for (size_t i = 0; i < interior->texGenEq.size(); i++)
+		{
+			texGens[i * 8 + 0] = interior->texGenEq[i].planeX.x;
+			texGens[i * 8 + 1] = interior->texGenEq[i].planeX.y;
+			texGens[i * 8 + 2] = interior->texGenEq[i].planeX.z;
+			texGens[i * 8 + 3] = interior->texGenEq[i].planeX.d;
+			texGens[i * 8 + 4] = interior->texGenEq[i].planeY.x;
+			texGens[i * 8 + 5] = interior->texGenEq[i].planeY.y;
+			texGens[i * 8 + 6] = interior->texGenEq[i].planeY.z;
+			texGens[i * 8 + 7] = interior->texGenEq[i].planeY.d;
+		}
+		return DIFBUILDER_OK;
+	}
+
+	int interior_get_winding_count(DIF::Interior *interior)
+	{
+		if (interior == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_winding_count: null interior");
+			return -1;
+		}
+		return (int)interior->index.size();
+	}
+
+	int interior_get_windings(DIF::Interior *interior, int *windings, int capacity)
+	{
+		if (interior == NULL || windings == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_windings: null argument");
+		if (capacity < (int)interior->index.size())
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_windings: capacity " + std::to_string(capacity) + " is less than " + std::to_string(interior->index.size()) + " indices");
+
+		for (size_t i = 0; i < interior->index.size(); i++)
+			windings[i] = (int)interior->index[i];
+		return DIFBUILDER_OK;
+	}
+
+	int interior_get_surface_count(DIF::Interior *interior)
+	{
+		if (interior == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_surface_count: null interior");
+			return -1;
+		}
+		return (int)interior->surface.size();
+	}
+
+	int interior_get_surface(DIF::Interior *interior, int surfaceIndex, int *windingStart, int *windingCount, int *planeIndex, int *planeFlipped, int *textureIndex, int *texGenIndex)
+	{
+		if (interior == NULL || windingStart == NULL || windingCount == NULL || planeIndex == NULL || planeFlipped == NULL || textureIndex == NULL || texGenIndex == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_surface: null argument");
+		if (surfaceIndex < 0 || surfaceIndex >= (int)interior->surface.size())
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_surface: surface index " + std::to_string(surfaceIndex) + " out of range");
+
+		const DIF::Interior::Surface &surface = interior->surface[surfaceIndex];
+		*windingStart = (int)surface.windingStart;
+		*windingCount = (int)surface.windingCount;
+		*planeIndex = (int)surface.planeIndex;
+		*planeFlipped = surface.planeFlipped ? 1 : 0;
+		*textureIndex = (int)surface.textureIndex;
+		*texGenIndex = (int)surface.texGenIndex;
+		return DIFBUILDER_OK;
+	}
+
+	int interior_get_material_count(DIF::Interior *interior)
+	{
+		if (interior == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_material_count: null interior");
+			return -1;
+		}
+		return (int)interior->materialName.size();
+	}
+
+	// Returns NULL on failure, the string is owned by the dif
+	const char *interior_get_material_name(DIF::Interior *interior, int materialIndex)
+	{
+		if (interior == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_material_name: null interior");
+			return NULL;
+		}
+		if (materialIndex < 0 || materialIndex >= (int)interior->materialName.size())
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "interior_get_material_name: material index " + std::to_string(materialIndex) + " out of range");
+			return NULL;
+		}
+		return interior->materialName[materialIndex].c_str();
+	}
+
+	int dif_path_follower_count(DIF::DIF *dif)
+	{
+		if (dif == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "dif_path_follower_count: null dif");
+			return -1;
+		}
+		return (int)dif->interiorPathFollower.size();
+	}
+
+	// name and datablock are owned by the dif, offset must hold 3 floats
+	int dif_get_path_follower(DIF::DIF *dif, int followerIndex, const char **name, const char **datablock, int *interiorResIndex, float *offset, int *wayPointCount)
+	{
+		DIF::InteriorPathFollower *follower = get_path_follower(dif, followerIndex, "dif_get_path_follower");
+		if (follower == NULL)
+			return DIFBUILDER_INVALID_ARGUMENT;
+		if (name == NULL || datablock == NULL || interiorResIndex == NULL || offset == NULL || wayPointCount == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "dif_get_path_follower: null output");
+
+		*name = follower->name.c_str();
+		*datablock = follower->datablock.c_str();
+		*interiorResIndex = (int)follower->interiorResIndex;
+		offset[0] = follower->offset.x;
+		offset[1] = follower->offset.y;
+		offset[2] = follower->offset.z;
+		*wayPointCount = (int)follower->wayPoint.size();
+		return DIFBUILDER_OK;
+	}
+
+	// position must hold 3 floats and rotation 4 (x, y, z, w)
+	int path_follower_get_way_point(DIF::DIF *dif, int followerIndex, int wayPointIndex, float *position, float *rotation, int *msToNext, int *smoothingType)
+	{
+		DIF::InteriorPathFollower *follower = get_path_follower(dif, followerIndex, "path_follower_get_way_point");
+		if (follower == NULL)
+			return DIFBUILDER_INVALID_ARGUMENT;
+		if (wayPointIndex < 0 || wayPointIndex >= (int)follower->wayPoint.size())
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "path_follower_get_way_point: way point index " + std::to_string(wayPointIndex) + " out of range");
+		if (position == NULL || rotation == NULL || msToNext == NULL || smoothingType == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "path_follower_get_way_point: null output");
+
+		position[0] = follower->wayPoint[wayPointIndex].position.x;
+		position[1] = follower->wayPoint[wayPointIndex].position.y;
+		position[2] = follower->wayPoint[wayPointIndex].position.z;
+		rotation[0] = follower->wayPoint[wayPointIndex].rotation.x;
+		rotation[1] = follower->wayPoint[wayPointIndex].rotation.y;
+		rotation[2] = follower->wayPoint[wayPointIndex].rotation.z;
+		rotation[3] = follower->wayPoint[wayPointIndex].rotation.w;
+		*msToNext = (int)follower->wayPoint[wayPointIndex].msToNext;
+		*smoothingType = (int)follower->wayPoint[wayPointIndex].smoothingType;
+		return DIFBUILDER_OK;
+	}
+
+	int dif_game_entity_count(DIF::DIF *dif)
+	{
+		if (dif == NULL)
+		{
+			fail(DIFBUILDER_INVALID_ARGUMENT, "dif_game_entity_count: null dif");
+			return -1;
+		}
+		return (int)dif->gameEntity.size();
+	}
+
+	// gameClass and datablock are owned by the dif, position must hold 3 floats
+	int dif_get_game_entity(DIF::DIF *dif, int entityIndex, const char **gameClass, const char **datablock, float *position, int *propertyCount)
+	{
+		DIF::GameEntity *entity = get_game_entity(dif, entityIndex, "dif_get_game_entity");
+		if (entity == NULL)
+			return DIFBUILDER_INVALID_ARGUMENT;
+		if (gameClass == NULL || datablock == NULL || position == NULL || propertyCount == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "dif_get_game_entity: null output");
+
+		*gameClass = entity->gameClass.c_str();
+		*datablock = entity->datablock.c_str();
+		position[0] = entity->position.x;
+		position[1] = entity->position.y;
+		position[2] = entity->position.z;
+		*propertyCount = (int)entity->properties.size();
+		return DIFBUILDER_OK;
+	}
+
+	// key and value are owned by the dif
+	int game_entity_get_property(DIF::DIF *dif, int entityIndex, int propertyIndex, const char **key, const char **value)
+	{
+		DIF::GameEntity *entity = get_game_entity(dif, entityIndex, "game_entity_get_property");
+		if (entity == NULL)
+			return DIFBUILDER_INVALID_ARGUMENT;
+		if (propertyIndex < 0 || propertyIndex >= (int)entity->properties.size())
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "game_entity_get_property: property index " + std::to_string(propertyIndex) + " out of range");
+		if (key == NULL || value == NULL)
+			return fail(DIFBUILDER_INVALID_ARGUMENT, "game_entity_get_property: null output");
+
+		*key = entity->properties[propertyIndex].first.c_str();
+		*value = entity->properties[propertyIndex].second.c_str();
+		return DIFBUILDER_OK;
 	}
 }
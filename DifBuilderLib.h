@@ -7,35 +7,104 @@
 #define PLUGIN_API
 #endif
 
+// Status codes returned by the functions below, get_last_error_message describes the last failure
+#define DIFBUILDER_OK 0
+#define DIFBUILDER_INVALID_ARGUMENT 1
+#define DIFBUILDER_ERROR 2
+
+// Engines accepted by write_dif_versioned
+#define DIFBUILDER_ENGINE_MBG 0
+#define DIFBUILDER_ENGINE_TGE 1
+#define DIFBUILDER_ENGINE_TGEA 2
+
+// Marker smoothing types accepted by push_marker
+#define DIFBUILDER_SMOOTHING_LINEAR 0
+#define DIFBUILDER_SMOOTHING_SPLINE 1
+#define DIFBUILDER_SMOOTHING_ACCELERATE 2
+
 extern "C"
 {
+	PLUGIN_API const char *get_last_error_message();
+
 	PLUGIN_API DIF::DIFBuilder *new_difbuilder();
 
 	PLUGIN_API void dispose_difbuilder(DIF::DIFBuilder *difbuilder);
 
 	PLUGIN_API void dispose_dif(DIF::DIF *dif);
 
-	PLUGIN_API void add_triangle(DIF::DIFBuilder *difbuilder, float *p1, float *p2, float *p3, float *uv1, float *uv2, float *uv3, float *n, char *material);
+	PLUGIN_API int add_triangle(DIF::DIFBuilder *difbuilder, float *p1, float *p2, float *p3, float *uv1, float *uv2, float *uv3, float *n, char *material);
 
 	PLUGIN_API DIF::DIF *build(DIF::DIFBuilder *difbuilder);
 
-	PLUGIN_API void add_pathed_interior(DIF::DIFBuilder *difbuilder, DIF::DIF *difptr, std::vector<DIF::DIFBuilder::Marker> *markerlist);
+	PLUGIN_API int add_pathed_interior(DIF::DIFBuilder *difbuilder, DIF::DIF *difptr, std::vector<DIF::DIFBuilder::Marker> *markerlist);
 
-	PLUGIN_API void write_dif(DIF::DIF *dif, char *path);
+	PLUGIN_API int write_dif(DIF::DIF *dif, char *path);
+
+	PLUGIN_API int write_dif_versioned(DIF::DIF *dif, char *path, int engine, int interiorVersion);
 
 	PLUGIN_API std::vector<DIF::DIFBuilder::Marker> *new_marker_list();
 
 	PLUGIN_API void dispose_marker_list(std::vector<DIF::DIFBuilder::Marker> *markerlist);
 
-	PLUGIN_API void push_marker(std::vector<DIF::DIFBuilder::Marker> *markerlist, float *pos, int msToNext, int initialTargetPosition);
+	PLUGIN_API int push_marker(std::vector<DIF::DIFBuilder::Marker> *markerlist, float *pos, int msToNext, int initialTargetPosition, int smoothing);
 
-	PLUGIN_API void add_game_entity(DIF::DIF *dif, char *gameClass, char *datablock, float *pos, DIF::Dictionary *dict);
+	PLUGIN_API int add_game_entity(DIF::DIF *dif, char *gameClass, char *datablock, float *pos, DIF::Dictionary *dict);
 
-	PLUGIN_API void add_trigger(DIF::DIFBuilder *difbuilder, float *position, char *name, char *datablock, DIF::Dictionary *props);
+	PLUGIN_API int add_trigger(DIF::DIFBuilder *difbuilder, float *position, char *name, char *datablock, DIF::Dictionary *props);
 
 	PLUGIN_API DIF::Dictionary *new_dict();
 
 	PLUGIN_API void dispose_dict(DIF::Dictionary *dict);
 
-	PLUGIN_API void add_dict_kvp(DIF::Dictionary *dict, char *key, char *value);
+	PLUGIN_API int add_dict_kvp(DIF::Dictionary *dict, char *key, char *value);
+
+	PLUGIN_API DIF::DIF *read_dif(char *path);
+
+	PLUGIN_API int dif_interior_count(DIF::DIF *dif);
+
+	PLUGIN_API DIF::Interior *dif_get_interior(DIF::DIF *dif, int interiorIndex);
+
+	PLUGIN_API int dif_sub_object_count(DIF::DIF *dif);
+
+	PLUGIN_API DIF::Interior *dif_get_sub_object(DIF::DIF *dif, int subObjectIndex);
+
+	PLUGIN_API int interior_get_point_count(DIF::Interior *interior);
+
+	PLUGIN_API int interior_get_points(DIF::Interior *interior, float *points, int capacity);
+
+	PLUGIN_API int interior_get_normal_count(DIF::Interior *interior);
+
+	PLUGIN_API int interior_get_normals(DIF::Interior *interior, float *normals, int capacity);
+
+	PLUGIN_API int interior_get_plane_count(DIF::Interior *interior);
+
+	PLUGIN_API int interior_get_planes(DIF::Interior *interior, int *normalIndices, float *distances, int capacity);
+
+	PLUGIN_API int interior_get_tex_gen_count(DIF::Interior *interior);
+
+	PLUGIN_API int interior_get_tex_gens(DIF::Interior *interior, float *texGens, int capacity);
+
+	PLUGIN_API int interior_get_winding_count(DIF::Interior *interior);
+
+	PLUGIN_API int interior_get_windings(DIF::Interior *interior, int *windings, int capacity);
+
+	PLUGIN_API int interior_get_surface_count(DIF::Interior *interior);
+
+	PLUGIN_API int interior_get_surface(DIF::Interior *interior, int surfaceIndex, int *windingStart, int *windingCount, int *planeIndex, int *planeFlipped, int *textureIndex, int *texGenIndex);
+
+	PLUGIN_API int interior_get_material_count(DIF::Interior *interior);
+
+	PLUGIN_API const char *interior_get_material_name(DIF::Interior *interior, int materialIndex);
+
+	PLUGIN_API int dif_path_follower_count(DIF::DIF *dif);
+
+	PLUGIN_API int dif_get_path_follower(DIF::DIF *dif, int followerIndex, const char **name, const char **datablock, int *interiorResIndex, float *offset, int *wayPointCount);
+
+	PLUGIN_API int path_follower_get_way_point(DIF::DIF *dif, int followerIndex, int wayPointIndex, float *position, float *rotation, int *msToNext, int *smoothingType);
+
+	PLUGIN_API int dif_game_entity_count(DIF::DIF *dif);
+
+	PLUGIN_API int dif_get_game_entity(DIF::DIF *dif, int entityIndex, const char **gameClass, const char **datablock, float *position, int *propertyCount);
+
+	PLUGIN_API int game_entity_get_property(DIF::DIF *dif, int entityIndex, int propertyIndex, const char **key, const char **value);
 }
\ No newline at end of file
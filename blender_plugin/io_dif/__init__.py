@@ -30,6 +30,7 @@ from bpy.props import (
     EnumProperty,
     PointerProperty,
 )
+from .util import marker_smoothing_types
 from bpy_extras.io_utils import (
     ImportHelper,
     ExportHelper,
@@ -88,6 +89,11 @@ class InteriorSettings(bpy.types.PropertyGroup):
     )
 
     marker_path = PointerProperty(type=bpy.types.Curve, name="Marker Path")
+    marker_smoothing = EnumProperty(
+        name="Marker Smoothing",
+        items=marker_smoothing_types,
+        default="linear",
+    )
     game_entity_datablock = StringProperty(name="Datablock")
     game_entity_gameclass = StringProperty(name="Game Class")
     game_entity_properties = CollectionProperty(
@@ -111,6 +117,8 @@ class InteriorPanel(bpy.types.Panel):
         if context.object.dif_props.interior_type == "pathed_interior":
             sublayout = layout.row()
             sublayout.prop(context.object.dif_props, "marker_path")
+            sublayout = layout.row()
+            sublayout.prop(context.object.dif_props, "marker_smoothing")
         if context.object.dif_props.interior_type == "game_entity":
             sublayout = layout.row()
             sublayout.prop(context.object.dif_props, "game_entity_datablock")
@@ -148,6 +156,12 @@ class ImportDIF(bpy.types.Operator, ImportHelper):
         options={"HIDDEN"},
     )
 
+    fix_winding = BoolProperty(
+        name="Fix Winding Order",
+        description="Orient every triangle to face the same way as its surface plane, so normals point outwards",
+        default=True,
+    )
+
     check_extension = True
 
     def execute(self, context):
@@ -167,10 +181,13 @@ class ImportDIF(bpy.types.Operator, ImportHelper):
 
             keywords["relpath"] = os.path.dirname(bpy.data.filepath)
 
-        return import_dif.load(context, **keywords)
+        problems = import_dif.load(context, **keywords)
+        for problem in problems:
+            self.report({"WARNING"}, problem)
+        return {"FINISHED"}
 
     def draw(self, context):
-        pass
+        self.layout.prop(self, "fix_winding")
 
 
 class ExportDIF(bpy.types.Operator, ExportHelper):
@@ -224,13 +241,20 @@ class ExportDIF(bpy.types.Operator, ExportHelper):
         default=False,
     )
 
+    texturepath = StringProperty(
+        name="Texture Search Path",
+        description="Game directory the DIF will be placed in, used to check that every exported material resolves to a texture file there or in a parent directory up to the game root. Leave empty to skip the check",
+        default="",
+        subtype="DIR_PATH",
+    )
+
     check_extension = True
 
     def execute(self, context):
         from . import export_dif
 
         keywords = self.as_keywords(ignore=("check_existing", "filter_glob"))
-        export_dif.save(
+        problems = export_dif.save(
             context,
             keywords["filepath"],
             keywords.get("flip", False),
@@ -239,7 +263,10 @@ class ExportDIF(bpy.types.Operator, ExportHelper):
             keywords.get("applymodifiers", True),
             keywords.get("exportvisible", True),
             keywords.get("exportselected", False),
+            keywords.get("texturepath", ""),
         )
+        for problem in problems:
+            self.report({"WARNING"}, problem)
         return {"FINISHED"}
 
 
@@ -8,6 +8,7 @@ from pathlib import Path
 from bpy.types import Curve, Image, Material, Mesh, Object, ShaderNodeTexImage
 from bpy_extras.wm_utils.progress_report import ProgressReport, ProgressReportSubstep
 from mathutils import Quaternion, Vector
+from .util import smoothing_type_value, texture_extensions, texture_search_dirs
 
 dllpath = os.path.join(os.path.dirname(os.path.realpath(__file__)), "DifBuilderLib.dll")
 difbuilderlib = None
@@ -18,6 +19,10 @@ except:
         "There was an error loading the necessary dll required for dif export. Please download the plugin from the proper location: https://github.com/RandomityGuy/io_dif/releases"
     )
 
+# Matches DIFBUILDER_OK in DifBuilderLib.h
+DIFBUILDER_OK = 0
+
+difbuilderlib.get_last_error_message.restype = ctypes.c_char_p
 difbuilderlib.new_difbuilder.restype = ctypes.c_void_p
 difbuilderlib.dispose_difbuilder.argtypes = [ctypes.c_void_p]
 difbuilderlib.add_triangle.argtypes = [
@@ -50,6 +55,7 @@ difbuilderlib.push_marker.argtypes = [
     ctypes.POINTER(ctypes.c_float),
     ctypes.c_int,
     ctypes.c_int,
+    ctypes.c_int,
 ]
 difbuilderlib.add_game_entity.argtypes = [
     ctypes.c_void_p,
@@ -73,36 +79,143 @@ difbuilderlib.add_trigger.argtypes = [
     ctypes.c_void_p,
 ]
 
+difbuilderlib.read_dif.argtypes = [ctypes.c_char_p]
+difbuilderlib.read_dif.restype = ctypes.c_void_p
+for name in ["dif_interior_count", "dif_sub_object_count", "dif_path_follower_count", "dif_game_entity_count"]:
+    getattr(difbuilderlib, name).argtypes = [ctypes.c_void_p]
+for name in ["dif_get_interior", "dif_get_sub_object"]:
+    getattr(difbuilderlib, name).argtypes = [ctypes.c_void_p, ctypes.c_int]
+    getattr(difbuilderlib, name).restype = ctypes.c_void_p
+for name in [
+    "interior_get_point_count",
+    "interior_get_normal_count",
+    "interior_get_plane_count",
+    "interior_get_tex_gen_count",
+    "interior_get_winding_count",
+    "interior_get_surface_count",
+    "interior_get_material_count",
+]:
+    getattr(difbuilderlib, name).argtypes = [ctypes.c_void_p]
+for name in ["interior_get_points", "interior_get_normals", "interior_get_tex_gens"]:
+    getattr(difbuilderlib, name).argtypes = [
+        ctypes.c_void_p,
+        ctypes.POINTER(ctypes.c_float),
+        ctypes.c_int,
+    ]
+difbuilderlib.interior_get_planes.argtypes = [
+    ctypes.c_void_p,
+    ctypes.POINTER(ctypes.c_int),
+    ctypes.POINTER(ctypes.c_float),
+    ctypes.c_int,
+]
+difbuilderlib.interior_get_windings.argtypes = [
+    ctypes.c_void_p,
+    ctypes.POINTER(ctypes.c_int),
+    ctypes.c_int,
+]
+difbuilderlib.interior_get_surface.argtypes = [ctypes.c_void_p, ctypes.c_int] + [
+    ctypes.POINTER(ctypes.c_int)
+] * 6
+difbuilderlib.interior_get_material_name.argtypes = [ctypes.c_void_p, ctypes.c_int]
+difbuilderlib.interior_get_material_name.restype = ctypes.c_char_p
+difbuilderlib.dif_get_path_follower.argtypes = [
+    ctypes.c_void_p,
+    ctypes.c_int,
+    ctypes.POINTER(ctypes.c_char_p),
+    ctypes.POINTER(ctypes.c_char_p),
+    ctypes.POINTER(ctypes.c_int),
+    ctypes.POINTER(ctypes.c_float),
+    ctypes.POINTER(ctypes.c_int),
+]
+difbuilderlib.path_follower_get_way_point.argtypes = [
+    ctypes.c_void_p,
+    ctypes.c_int,
+    ctypes.c_int,
+    ctypes.POINTER(ctypes.c_float),
+    ctypes.POINTER(ctypes.c_float),
+    ctypes.POINTER(ctypes.c_int),
+    ctypes.POINTER(ctypes.c_int),
+]
+difbuilderlib.dif_get_game_entity.argtypes = [
+    ctypes.c_void_p,
+    ctypes.c_int,
+    ctypes.POINTER(ctypes.c_char_p),
+    ctypes.POINTER(ctypes.c_char_p),
+    ctypes.POINTER(ctypes.c_float),
+    ctypes.POINTER(ctypes.c_int),
+]
+difbuilderlib.game_entity_get_property.argtypes = [
+    ctypes.c_void_p,
+    ctypes.c_int,
+    ctypes.c_int,
+    ctypes.POINTER(ctypes.c_char_p),
+    ctypes.POINTER(ctypes.c_char_p),
+]
+
 
 scene = bpy.context.scene
 
 obj = bpy.context.active_object
 
 
+def last_error():
+    return Exception(difbuilderlib.get_last_error_message().decode("ascii", "replace"))
+
+
+def check_status(status):
+    if status != DIFBUILDER_OK:
+        raise last_error()
+
+
+def encode_string(value: str):
+    # DIF strings are written with a single length byte
+    encoded = value.encode("ascii")
+    if len(encoded) > 255:
+        raise Exception(
+            'String "%s..." is %d characters long, DIF strings can be at most 255 characters'
+            % (value[:32], len(encoded))
+        )
+    return encoded
+
+
 class MarkerList:
     def __init__(self):
         self.__ptr__ = difbuilderlib.new_marker_list()
+        if self.__ptr__ is None:
+            raise last_error()
 
     def __del__(self):
         difbuilderlib.dispose_marker_list(self.__ptr__)
 
-    def push_marker(self, vec, msToNext, initialPathPosition):
+    def push_marker(self, vec, msToNext, initialPathPosition, smoothing="linear"):
         vecarr = (ctypes.c_float * len(vec))(*vec)
-        difbuilderlib.push_marker(self.__ptr__, vecarr, msToNext, initialPathPosition)
+        check_status(
+            difbuilderlib.push_marker(
+                self.__ptr__,
+                vecarr,
+                msToNext,
+                initialPathPosition,
+                smoothing_type_value(smoothing),
+            )
+        )
 
 
 class DIFDict:
     def __init__(self):
         self.__ptr__ = difbuilderlib.new_dict()
+        if self.__ptr__ is None:
+            raise last_error()
 
     def __del__(self):
         difbuilderlib.dispose_dict(self.__ptr__)
 
     def add_kvp(self, key, value):
-        difbuilderlib.add_dict_kvp(
-            self.__ptr__,
-            ctypes.create_string_buffer(key.encode("ascii")),
-            ctypes.create_string_buffer(value.encode("ascii")),
+        check_status(
+            difbuilderlib.add_dict_kvp(
+                self.__ptr__,
+                ctypes.create_string_buffer(encode_string(key)),
+                ctypes.create_string_buffer(encode_string(value)),
+            )
         )
 
 
@@ -114,8 +227,10 @@ class Dif:
         difbuilderlib.dispose_dif(self.__ptr__)
 
     def write_dif(self, path):
-        difbuilderlib.write_dif(
-            self.__ptr__, ctypes.create_string_buffer(path.encode("ascii"))
+        check_status(
+            difbuilderlib.write_dif(
+                self.__ptr__, ctypes.create_string_buffer(path.encode("ascii"))
+            )
         )
 
     def add_game_entity(self, gameClass, datablock, position, scale, properties: dict):
@@ -126,18 +241,22 @@ class Dif:
         propertydict.add_kvp("scale", "%.5f %.5f %.5f" % (scale[0], scale[1], scale[2]))
         if gameClass == "Trigger":
             propertydict.add_kvp("polyhedron", "0 0 0 1 0 0 0 -1 0 0 0 1")
-        difbuilderlib.add_game_entity(
-            self.__ptr__,
-            ctypes.create_string_buffer(gameClass.encode("ascii")),
-            ctypes.create_string_buffer(datablock.encode("ascii")),
-            vecarr,
-            propertydict.__ptr__,
+        check_status(
+            difbuilderlib.add_game_entity(
+                self.__ptr__,
+                ctypes.create_string_buffer(encode_string(gameClass)),
+                ctypes.create_string_buffer(encode_string(datablock)),
+                vecarr,
+                propertydict.__ptr__,
+            )
         )
 
 
 class DifBuilder:
     def __init__(self):
         self.__ptr__ = difbuilderlib.new_difbuilder()
+        if self.__ptr__ is None:
+            raise last_error()
 
     def __del__(self):
         difbuilderlib.dispose_difbuilder(self.__ptr__)
@@ -153,29 +272,239 @@ class DifBuilder:
 
         narr = (ctypes.c_float * len(n))(*n)
 
-        mat = ctypes.c_char_p(material.encode("ascii"))
+        mat = ctypes.c_char_p(encode_string(material))
 
-        difbuilderlib.add_triangle(
-            self.__ptr__, p3arr, p2arr, p1arr, uv3arr, uv2arr, uv1arr, narr, mat
+        check_status(
+            difbuilderlib.add_triangle(
+                self.__ptr__, p3arr, p2arr, p1arr, uv3arr, uv2arr, uv1arr, narr, mat
+            )
         )
 
     def add_pathed_interior(self, dif: Dif, markerlist: MarkerList):
-        difbuilderlib.add_pathed_interior(self.__ptr__, dif.__ptr__, markerlist.__ptr__)
+        check_status(
+            difbuilderlib.add_pathed_interior(
+                self.__ptr__, dif.__ptr__, markerlist.__ptr__
+            )
+        )
 
     # NONFUNCTIONAL, TRIGGERS ARENT GETTING CREATED WHEN PRESSING CREATE SUBS
     def add_trigger(self, datablock, name, position, scale, props: DIFDict):
         posarr = (ctypes.c_float * len(position))(*position)
         props.add_kvp("scale", f"{scale[0]} {scale[1]} {scale[2]}")
-        difbuilderlib.add_trigger(
-            self.__ptr__,
-            posarr,
-            ctypes.create_string_buffer(name.encode("ascii")),
-            ctypes.create_string_buffer(datablock.encode("ascii")),
-            props.__ptr__,
+        check_status(
+            difbuilderlib.add_trigger(
+                self.__ptr__,
+                posarr,
+                ctypes.create_string_buffer(encode_string(name)),
+                ctypes.create_string_buffer(encode_string(datablock)),
+                props.__ptr__,
+            )
         )
 
     def build(self):
-        return Dif(difbuilderlib.build(self.__ptr__))
+        ptr = difbuilderlib.build(self.__ptr__)
+        if ptr is None:
+            raise last_error()
+        return Dif(ptr)
+
+
+def check_count(count):
+    if count < 0:
+        raise last_error()
+    return count
+
+
+def decode_string(value: bytes):
+    return value.decode("ascii", "replace")
+
+
+class ReadInterior:
+    """
+    Copies an interior out of a DIF read by read_dif, in the same units the DIF stores them
+    """
+
+    def __init__(self, ptr):
+        point_count = check_count(difbuilderlib.interior_get_point_count(ptr))
+        points = (ctypes.c_float * (point_count * 3))()
+        check_status(difbuilderlib.interior_get_points(ptr, points, point_count))
+        self.points = [tuple(points[i * 3 : i * 3 + 3]) for i in range(point_count)]
+
+        normal_count = check_count(difbuilderlib.interior_get_normal_count(ptr))
+        normals = (ctypes.c_float * (normal_count * 3))()
+        check_status(difbuilderlib.interior_get_normals(ptr, normals, normal_count))
+        self.normals = [tuple(normals[i * 3 : i * 3 + 3]) for i in range(normal_count)]
+
+        # (normal index, distance), the normal index is not range checked
+        plane_count = check_count(difbuilderlib.interior_get_plane_count(ptr))
+        normal_indices = (ctypes.c_int * plane_count)()
+        distances = (ctypes.c_float * plane_count)()
+        check_status(
+            difbuilderlib.interior_get_planes(ptr, normal_indices, distances, plane_count)
+        )
+        self.planes = list(zip(normal_indices, distances))
+
+        # (planeX, planeY), each (x, y, z, d)
+        tex_gen_count = check_count(difbuilderlib.interior_get_tex_gen_count(ptr))
+        tex_gens = (ctypes.c_float * (tex_gen_count * 8))()
+        check_status(difbuilderlib.interior_get_tex_gens(ptr, tex_gens, tex_gen_count))
+        self.tex_gens = [
+            (tuple(tex_gens[i * 8 : i * 8 + 4]), tuple(tex_gens[i * 8 + 4 : i * 8 + 8]))
+            for i in range(tex_gen_count)
+        ]
+
+        winding_count = check_count(difbuilderlib.interior_get_winding_count(ptr))
+        windings = (ctypes.c_int * winding_count)()
+        check_status(difbuilderlib.interior_get_windings(ptr, windings, winding_count))
+        self.windings = list(windings)
+
+        # (windingStart, windingCount, planeIndex, planeFlipped, textureIndex, texGenIndex)
+        self.surfaces = []
+        for i in range(check_count(difbuilderlib.interior_get_surface_count(ptr))):
+            fields = [ctypes.c_int() for _ in range(6)]
+            check_status(
+                difbuilderlib.interior_get_surface(
+                    ptr, i, *[ctypes.byref(field) for field in fields]
+                )
+            )
+            self.surfaces.append(tuple(field.value for field in fields))
+
+        self.materials = []
+        for i in range(check_count(difbuilderlib.interior_get_material_count(ptr))):
+            name = difbuilderlib.interior_get_material_name(ptr, i)
+            if name is None:
+                raise last_error()
+            self.materials.append(decode_string(name))
+
+
+class ReadDif:
+    """
+    A DIF loaded through read_dif, every accessor copies its data out of the DLL
+    """
+
+    def __init__(self, ptr):
+        self.__ptr__ = ptr
+
+    def __del__(self):
+        difbuilderlib.dispose_dif(self.__ptr__)
+
+    def interiors(self):
+        return [
+            ReadInterior(self.__get_interior(difbuilderlib.dif_get_interior, i))
+            for i in range(check_count(difbuilderlib.dif_interior_count(self.__ptr__)))
+        ]
+
+    def sub_objects(self):
+        return [
+            ReadInterior(self.__get_interior(difbuilderlib.dif_get_sub_object, i))
+            for i in range(
+                check_count(difbuilderlib.dif_sub_object_count(self.__ptr__))
+            )
+        ]
+
+    def __get_interior(self, getter, index):
+        ptr = getter(self.__ptr__, index)
+        if ptr is None:
+            raise last_error()
+        return ptr
+
+    def path_followers(self):
+        followers = []
+        for i in range(check_count(difbuilderlib.dif_path_follower_count(self.__ptr__))):
+            name = ctypes.c_char_p()
+            datablock = ctypes.c_char_p()
+            interior_index = ctypes.c_int()
+            offset = (ctypes.c_float * 3)()
+            way_point_count = ctypes.c_int()
+            check_status(
+                difbuilderlib.dif_get_path_follower(
+                    self.__ptr__,
+                    i,
+                    ctypes.byref(name),
+                    ctypes.byref(datablock),
+                    ctypes.byref(interior_index),
+                    offset,
+                    ctypes.byref(way_point_count),
+                )
+            )
+
+            # (position, rotation (x, y, z, w), msToNext, smoothingType)
+            way_points = []
+            for j in range(way_point_count.value):
+                position = (ctypes.c_float * 3)()
+                rotation = (ctypes.c_float * 4)()
+                ms_to_next = ctypes.c_int()
+                smoothing = ctypes.c_int()
+                check_status(
+                    difbuilderlib.path_follower_get_way_point(
+                        self.__ptr__,
+                        i,
+                        j,
+                        position,
+                        rotation,
+                        ctypes.byref(ms_to_next),
+                        ctypes.byref(smoothing),
+                    )
+                )
+                way_points.append(
+                    (tuple(position), tuple(rotation), ms_to_next.value, smoothing.value)
+                )
+
+            followers.append(
+                {
+                    "name": decode_string(name.value),
+                    "datablock": decode_string(datablock.value),
+                    "interiorResIndex": interior_index.value,
+                    "offset": tuple(offset),
+                    "wayPoints": way_points,
+                }
+            )
+        return followers
+
+    def game_entities(self):
+        entities = []
+        for i in range(check_count(difbuilderlib.dif_game_entity_count(self.__ptr__))):
+            game_class = ctypes.c_char_p()
+            datablock = ctypes.c_char_p()
+            position = (ctypes.c_float * 3)()
+            property_count = ctypes.c_int()
+            check_status(
+                difbuilderlib.dif_get_game_entity(
+                    self.__ptr__,
+                    i,
+                    ctypes.byref(game_class),
+                    ctypes.byref(datablock),
+                    position,
+                    ctypes.byref(property_count),
+                )
+            )
+
+            properties = {}
+            for j in range(property_count.value):
+                key = ctypes.c_char_p()
+                value = ctypes.c_char_p()
+                check_status(
+                    difbuilderlib.game_entity_get_property(
+                        self.__ptr__, i, j, ctypes.byref(key), ctypes.byref(value)
+                    )
+                )
+                properties[decode_string(key.value)] = decode_string(value.value)
+
+            entities.append(
+                {
+                    "gameClass": decode_string(game_class.value),
+                    "datablock": decode_string(datablock.value),
+                    "position": tuple(position),
+                    "properties": properties,
+                }
+            )
+        return entities
+
+
+def read_dif(path):
+    ptr = difbuilderlib.read_dif(ctypes.create_string_buffer(path.encode("ascii")))
+    if ptr is None:
+        raise last_error()
+    return ReadDif(ptr)
 
 
 def mesh_triangulate(me):
@@ -201,6 +530,47 @@ def resolve_texture(mat: Material):
     return Path(img.image.filepath).stem
 
 
+def check_textures(materials, texturepath):
+    if not os.path.isdir(texturepath):
+        return ["Texture search path %s is not a directory" % texturepath]
+
+    # Textures are looked up the same way the engine does, in the DIF's directory and then its parents
+    problems = []
+    search_dirs = []
+    for dirname in texture_search_dirs(
+        os.path.normpath(texturepath), stop_at_game_root=True
+    ):
+        try:
+            filenames = os.listdir(dirname)
+        except OSError as e:
+            problems.append("Cannot search %s for textures: %s" % (dirname, e.strerror))
+            continue
+
+        textures = {}
+        for filename in filenames:
+            stem, ext = os.path.splitext(filename)
+            if ext[1:].lower() in texture_extensions:
+                textures.setdefault(stem.lower(), set()).add(stem)
+        search_dirs.append(textures)
+
+    for material in sorted(materials):
+        found = None
+        for textures in search_dirs:
+            found = textures.get(material.lower())
+            if found is not None:
+                break
+
+        if found is None:
+            problems.append("Missing texture: %s" % material)
+        elif material not in found:
+            problems.append(
+                "Miscased texture: %s (found %s)"
+                % (material, ", ".join(sorted(found)))
+            )
+
+    return problems
+
+
 def get_offset(depsgraph, applymodifiers=True):
     obs = bpy.context.scene.objects
     minv = [1e9, 1e9, 1e9]
@@ -226,7 +596,9 @@ def get_offset(depsgraph, applymodifiers=True):
     return off
 
 
-def build_pathed_interior(ob: Object, marker_ob: Curve, offset, flip, double):
+def build_pathed_interior(
+    ob: Object, marker_ob: Curve, offset, flip, double, materials=None
+):
     difbuilder = DifBuilder()
     mesh = ob.to_mesh()
     mesh_triangulate(mesh)
@@ -261,6 +633,8 @@ def build_pathed_interior(ob: Object, marker_ob: Curve, offset, flip, double):
             if poly.material_index != None
             else "NULL"
         )
+        if materials is not None:
+            materials.add(material)
 
         if not flip:
             difbuilder.add_triangle(p1, p2, p3, uv1, uv2, uv3, n, material)
@@ -284,7 +658,9 @@ def build_pathed_interior(ob: Object, marker_ob: Curve, offset, flip, double):
     marker_list = MarkerList()
 
     for pt in marker_pts:
-        marker_list.push_marker(pt.co, msToNext, initialPathPosition)
+        marker_list.push_marker(
+            pt.co, msToNext, initialPathPosition, ob.dif_props.marker_smoothing
+        )
 
     return (dif, marker_list)
 
@@ -320,6 +696,7 @@ def save(
     applymodifiers=True,
     exportvisible=True,
     exportselected=False,
+    texturepath="",
 ):
     import bpy
     import bmesh
@@ -336,6 +713,10 @@ def save(
 
     tris = 0
 
+    # Names of the textures the exported triangles use, for check_textures
+    check_materials = texturepath != ""
+    materials = set()
+
     def save_mesh(obj: Object, mesh: Mesh, offset, flip=False, double=False):
         import bpy
 
@@ -378,6 +759,8 @@ def save(
                 if poly.material_index != None
                 else "NULL"
             )
+            if check_materials:
+                materials.add(material)
 
             if not flip:
                 difbuilder.add_triangle(p1, p2, p3, uv1, uv2, uv3, n, material)
@@ -395,52 +778,90 @@ def save(
     mp_list = []
     game_entities: list[Object] = []
 
-    for ob in obs:
-        if exportvisible:
-            if not ob.visible_get():
+    with ProgressReport(context.window_manager) as progress:
+        progress.enter_substeps(len(obs), "Collecting objects...")
+
+        for ob in obs:
+            progress.step()
+
+            if exportvisible:
+                if not ob.visible_get():
+                    continue
+
+            ob_eval = ob.evaluated_get(depsgraph) if applymodifiers else ob
+
+            dif_props = ob_eval.dif_props
+
+            if dif_props.interior_type == "game_entity":
+                game_entities.append(ob_eval)
+
+            try:
+                me = ob_eval.to_mesh()
+            except RuntimeError:
                 continue
 
-        ob_eval = ob.evaluated_get(depsgraph) if applymodifiers else ob
+            if dif_props.interior_type == "static_interior":
+                me.transform(ob_eval.matrix_world)
+                # A negative scale mirrors the mesh, which reverses the winding of every face
+                mirrored = ob_eval.matrix_world.determinant() < 0
+                save_mesh(ob_eval, me, off, flip != mirrored, double)
 
-        dif_props = ob_eval.dif_props
+            if dif_props.interior_type == "pathed_interior":
+                mp_list.append((ob_eval, dif_props.marker_path))
 
-        if dif_props.interior_type == "game_entity":
-            game_entities.append(ob_eval)
+        progress.leave_substeps()
 
-        try:
-            me = ob_eval.to_mesh()
-        except RuntimeError:
-            continue
+        mp_difs = []
 
-        if dif_props.interior_type == "static_interior":
-            me.transform(ob_eval.matrix_world)
-            save_mesh(ob_eval, me, off, flip, double)
+        progress.enter_substeps(len(mp_list), "Building pathed interiors...")
 
-        if dif_props.interior_type == "pathed_interior":
-            mp_list.append((ob_eval, dif_props.marker_path))
+        for (mp, curve) in mp_list:
+            progress.step("Building pathed interior " + mp.name + "...", 0)
+            mp_difs.append(
+                build_pathed_interior(
+                    mp,
+                    curve,
+                    off,
+                    flip,
+                    double,
+                    materials if check_materials else None,
+                )
+            )
+            progress.step()
 
-    mp_difs = []
+        progress.leave_substeps()
 
-    for (mp, curve) in mp_list:
-        mp_difs.append(build_pathed_interior(mp, curve, off, flip, double))
+        if tris != 0:
+            progress.enter_substeps(len(builders), "Building DIFs...")
 
-    if tris != 0:
-        for i in range(0, len(builders)):
-            if i == 0:
-                for (mpdif, markerlist) in mp_difs:
-                    builders[i].add_pathed_interior(mpdif, markerlist)
+            for i in range(0, len(builders)):
+                if i == 0:
+                    for (mpdif, markerlist) in mp_difs:
+                        builders[i].add_pathed_interior(mpdif, markerlist)
 
-            dif = builders[i].build()
+                difpath = str(Path(filepath).with_suffix("")) + str(i) + ".dif"
 
-            if i == 0:
-                for ge in game_entities:
-                    entity = build_game_entity(ge)
-                    dif.add_game_entity(
-                        entity[1],
-                        entity[0],
-                        [ge.location[i] + off[i] for i in range(0, 3)],
-                        entity[3],
-                        entity[2],
-                    )
+                # build() runs hull and poly list processing, name the DIF before it starts
+                progress.step("Building " + difpath + "...", 0)
+                dif = builders[i].build()
+
+                if i == 0:
+                    for ge in game_entities:
+                        entity = build_game_entity(ge)
+                        dif.add_game_entity(
+                            entity[1],
+                            entity[0],
+                            [ge.location[i] + off[i] for i in range(0, 3)],
+                            entity[3],
+                            entity[2],
+                        )
+
+                dif.write_dif(difpath)
+                progress.step("Exported " + difpath)
+
+            progress.leave_substeps()
+
+    if check_materials:
+        return check_textures(materials, bpy.path.abspath(texturepath))
 
-            dif.write_dif(str(Path(filepath).with_suffix("")) + str(i) + ".dif")
+    return []
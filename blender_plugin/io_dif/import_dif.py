@@ -8,7 +8,12 @@ import mathutils
 from .hxDif import *
 from bpy_extras.io_utils import unpack_list
 from bpy_extras.image_utils import load_image
-from .util import default_materials, resolve_texture, get_rgb_colors
+from .util import (
+    default_materials,
+    resolve_texture,
+    get_rgb_colors,
+    smoothing_type_identifier,
+)
 
 from bpy_extras.wm_utils.progress_report import ProgressReport, ProgressReportSubstep
 
@@ -39,7 +44,34 @@ def create_material(filepath, matname):
     return mat
 
 
-def create_mesh(filepath, interior: Interior):
+def is_surface_valid(interior: Interior, surface):
+    """
+    Checks that every index a surface refers to lies within the interior's arrays,
+    so that malformed files do not make the fan decoding walk off the end of them.
+    """
+    if surface.windingStart < 0 or (
+        surface.windingStart + surface.windingCount > len(interior.windings)
+    ):
+        return False
+
+    for i in range(surface.windingStart, surface.windingStart + surface.windingCount):
+        if not 0 <= interior.windings[i] < len(interior.points):
+            return False
+
+    plane_index = surface.planeIndex & ~0x8000
+    if not 0 <= plane_index < len(interior.planes):
+        return False
+    if not 0 <= interior.planes[plane_index].normalIndex < len(interior.normals):
+        return False
+    if not 0 <= surface.texGenIndex < len(interior.texGenEQs):
+        return False
+    if not 0 <= surface.textureIndex < len(interior.materialList):
+        return False
+
+    return True
+
+
+def create_mesh(filepath, interior: Interior, fix_winding=True):
     """
     :param Interior interior:
     :return:
@@ -56,7 +88,17 @@ def create_mesh(filepath, interior: Interior):
     for mat in interior.materialList:
         me.materials.append(create_material(filepath, mat))
 
+    invalid_surfaces = 0
+
     for surface in interior.surfaces:
+        # Degenerate surfaces produce no triangles
+        if surface.windingCount < 3:
+            continue
+
+        if not is_surface_valid(interior, surface):
+            invalid_surfaces += 1
+            continue
+
         for i in range(0, surface.windingCount - 2):
             if i % 2 == 0:
                 index0 = interior.windings[i + surface.windingStart + 2]
@@ -71,16 +113,23 @@ def create_mesh(filepath, interior: Interior):
             normal_index = interior.planes[surface.planeIndex & ~0x8000].normalIndex
             tex_gen = interior.texGenEQs[surface.texGenIndex]
 
-            normal = interior.normals[normal_index]
+            raw_normal = interior.normals[normal_index]
+            normal = mathutils.Vector((raw_normal.x, raw_normal.y, raw_normal.z))
             if plane_flipped:
-                normal.x *= -1
-                normal.y *= -1
-                normal.z *= -1
+                normal.negate()
 
             pt0 = interior.points[index0]
             pt1 = interior.points[index1]
             pt2 = interior.points[index2]
 
+            if fix_winding:
+                v0 = mathutils.Vector((pt0.x, pt0.y, pt0.z))
+                v1 = mathutils.Vector((pt1.x, pt1.y, pt1.z))
+                v2 = mathutils.Vector((pt2.x, pt2.y, pt2.z))
+                if (v1 - v0).cross(v2 - v0).dot(normal) < 0:
+                    index1, index2 = index2, index1
+                    pt1, pt2 = pt2, pt1
+
             def plane_to_uv(pt, plane):
                 return pt.x * plane.x + pt.y * plane.y + pt.z * plane.z + plane.d
 
@@ -116,6 +165,11 @@ def create_mesh(filepath, interior: Interior):
                 )
             )
 
+    # normals are stored per triangle corner, pick one for each vertex that is used
+    vertex_normals = {}
+    for (vertex_index, normal_index, _) in indices:
+        vertex_normals[vertex_index] = normals[normal_index]
+
     me.vertices.add(len(interior.points))
     for i in range(0, len(interior.points)):
         me.vertices[i].co = [
@@ -123,7 +177,9 @@ def create_mesh(filepath, interior: Interior):
             interior.points[i].y,
             interior.points[i].z,
         ]
-        me.vertices[i].normal = [normals[i].x, normals[i].y, normals[i].z]
+        if i in vertex_normals:
+            normal = vertex_normals[i]
+            me.vertices[i].normal = [normal.x, normal.y, normal.z]
 
     me.polygons.add(len(faces))
     me.loops.add(len(faces) * 3)
@@ -151,7 +207,7 @@ def create_mesh(filepath, interior: Interior):
     ob.empty_display_type = "SINGLE_ARROW"
     ob.empty_display_size = 0.5
 
-    return ob
+    return (ob, invalid_surfaces)
 
 
 def load(
@@ -167,7 +223,8 @@ def load(
     use_groups_as_vgroups=False,
     use_cycles=True,
     relpath=None,
-    global_matrix=None
+    global_matrix=None,
+    fix_winding=True
 ):
     """
     Called by the user interface or another script.
@@ -188,12 +245,24 @@ def load(
     scene = context.scene
     new_objects = []  # put new objects here
 
+    problems = []
+    invalid_surfaces = 0
+
     for interior in dif.interiors:
-        new_objects.append(create_mesh(filepath, interior))
+        (ob, skipped) = create_mesh(filepath, interior, fix_winding)
+        new_objects.append(ob)
+        invalid_surfaces += skipped
 
     pathedInteriors: list[Object] = []
     for pathedInterior in dif.subObjects:
-        pathedInteriors.append(create_mesh(filepath, pathedInterior))
+        (ob, skipped) = create_mesh(filepath, pathedInterior, fix_winding)
+        pathedInteriors.append(ob)
+        invalid_surfaces += skipped
+
+    if invalid_surfaces != 0:
+        problems.append(
+            "Skipped %d surfaces with out of range indices" % invalid_surfaces
+        )
 
     # Create new obj
     for obj in new_objects:
@@ -212,6 +281,25 @@ def load(
 
         waypoints: list[WayPoint] = mover.wayPoint
 
+        smoothings = []
+        for (i, waypt) in enumerate(waypoints):
+            smoothing = smoothing_type_identifier(waypt.smoothingType)
+            if smoothing is None:
+                problems.append(
+                    "Path %s: waypoint %d has invalid smoothing type %d"
+                    % (mover.name, i, waypt.smoothingType)
+                )
+            elif smoothing not in smoothings:
+                smoothings.append(smoothing)
+
+        if len(smoothings) > 1:
+            problems.append(
+                "Path %s: waypoints mix smoothing types %s, using %s"
+                % (mover.name, ", ".join(smoothings), smoothings[0])
+            )
+        if len(smoothings) != 0:
+            itr.dif_props.marker_smoothing = smoothings[0]
+
         markerpts = [
             (waypt.position.x, waypt.position.y, waypt.position.z)
             for waypt in waypoints
@@ -277,4 +365,4 @@ def load(
 
     # progress.leave_substeps("Done.")
 
-    return {"FINISHED"}
+    return problems
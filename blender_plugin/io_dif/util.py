@@ -26,23 +26,36 @@ default_materials = {
     "yellow": (255, 255, 0)
 }
 
+# Marker smoothing types as (identifier, name, description, value), values match DIFBUILDER_SMOOTHING_* in DifBuilderLib.h
+marker_smoothing_types = (
+    ("linear", "Linear", "Move at a constant speed between markers", 0),
+    ("spline", "Spline", "Follow a smooth curve through the markers", 1),
+    ("accelerate", "Accelerate", "Ease in and out at every marker", 2),
+)
+
 for name, color in default_materials.items():
     default_materials[name] = (color[0] / 255, color[1] / 255, color[2] / 255)
 
 for key, value in tuple(default_materials.items()):
     default_materials[key.lower()] = value
 
-def resolve_texture(filepath, name):
-    dirname = os.path.dirname(filepath)
+def smoothing_type_value(identifier):
+    for smoothing in marker_smoothing_types:
+        if smoothing[0] == identifier:
+            return smoothing[3]
 
-    while True:
-        texbase = os.path.join(dirname, name)
+def smoothing_type_identifier(value):
+    for smoothing in marker_smoothing_types:
+        if smoothing[3] == value:
+            return smoothing[0]
 
-        for extension in texture_extensions:
-            texname = texbase + "." + extension
+def texture_search_dirs(dirname, stop_at_game_root=False):
+    while True:
+        yield dirname
 
-            if os.path.isfile(texname):
-                return texname
+        # The game root holds main.cs, the engine never looks above it
+        if stop_at_game_root and os.path.isfile(os.path.join(dirname, "main.cs")):
+            break
 
         if os.path.ismount(dirname):
             break
@@ -52,6 +65,16 @@ def resolve_texture(filepath, name):
         if prevdir == dirname:
             break
 
+def resolve_texture(filepath, name):
+    for dirname in texture_search_dirs(os.path.dirname(filepath)):
+        texbase = os.path.join(dirname, name)
+
+        for extension in texture_extensions:
+            texname = texbase + "." + extension
+
+            if os.path.isfile(texname):
+                return texname
+
 def fractions():
     yield 0
 